@@ -30,11 +30,60 @@ use std::path::PathBuf;
 use std::str::FromStr;
 
 pub use rustyline::error::ReadlineError;
-type Result<T> = std::result::Result<T, ReadlineError>;
+type Result<T> = std::result::Result<T, PromptError>;
+
+/// Errors that can occur while prompting.
+///
+/// Unlike `ReadlineError`, this distinguishes a user-initiated cancellation
+/// (Ctrl-C) from end-of-input (Ctrl-D) and from other I/O failures, so
+/// callers can handle "the user cancelled" without matching on readline
+/// internals.
+#[derive(Debug)]
+pub enum PromptError {
+    /// The user cancelled input with Ctrl-C
+    Cancelled,
+    /// The user signalled end-of-input with Ctrl-D
+    Eof,
+    /// An I/O error occurred while reading input
+    Io(::std::io::Error),
+}
+
+impl ::std::fmt::Display for PromptError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            PromptError::Cancelled => write!(f, "prompt cancelled"),
+            PromptError::Eof => write!(f, "unexpected end of input"),
+            PromptError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl ::std::error::Error for PromptError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            PromptError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ReadlineError> for PromptError {
+    fn from(e: ReadlineError) -> Self {
+        match e {
+            ReadlineError::Interrupted => PromptError::Cancelled,
+            ReadlineError::Eof => PromptError::Eof,
+            ReadlineError::Io(e) => PromptError::Io(e),
+            e => PromptError::Io(::std::io::Error::new(::std::io::ErrorKind::Other, e.to_string())),
+        }
+    }
+}
 
 #[cfg(feature = "nightly")]
 use std::fmt::Display;
 
+#[cfg(feature = "rpassword")]
+use zeroize::Zeroize;
+
 /// Prompt until input can be parsed as `T`.
 ///
 /// Empty string input causes a re-prompt (including for `String`)
@@ -55,7 +104,7 @@ use std::fmt::Display;
 /// ```
 ///
 /// ## Errors
-/// Returns a `ReadlineError` if readline fails.
+/// Returns a `PromptError` if readline fails.
 /// Input that can't be coerced into the specified type results in re-prompting.
 pub fn prompt<T, S>(msg: S) -> Result<T>
 where
@@ -85,7 +134,7 @@ where
 /// ```
 ///
 /// ## Errors
-/// Returns a `ReadlineError` if readline fails.
+/// Returns a `PromptError` if readline fails.
 /// Input that can't be coerced into the specified type results in re-prompting.
 pub fn prompt_opt<T, S>(msg: S) -> Result<Option<T>>
 where
@@ -117,7 +166,7 @@ where
 /// ```
 ///
 /// ## Errors
-/// Returns a `ReadlineError` if readline fails.
+/// Returns a `PromptError` if readline fails.
 /// Input that can't be coerced into the specified type results in re-prompting.
 pub fn prompt_default<T, S>(msg: S, default: T) -> Result<T>
 where
@@ -127,6 +176,381 @@ where
     T::prompt_default(msg, default)
 }
 
+/// Prompt until the input parses into `T` and falls within `[min, max]`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use promptly::prompt_range;
+///
+/// let age: u32 = prompt_range("Enter your age", 0, 120)?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+/// Input that can't be coerced into `T`, or falls outside the range, results in re-prompting.
+pub fn prompt_range<T, S>(msg: S, min: T, max: T) -> Result<T>
+where
+    T: FromStr + PartialOrd + ::std::fmt::Display + Copy,
+    <T as FromStr>::Err: ::std::error::Error,
+    S: AsRef<str>,
+{
+    let prompt = format!("{} {}", msg.as_ref(), style_hint(&format!("({}-{})", min, max)));
+    Prompter::new().prompt_then(prompt, |s| {
+        let val = T::from_str(s.as_ref()).map_err(|e| e.to_string())?;
+        check_range(val, min, max)
+    })
+}
+
+/// Validate that `val` falls within `[min, max]`, rejecting `NaN` (for which
+/// both `val < min` and `val > max` are `false`, so it would otherwise sail
+/// through the bounds check as "in range").
+fn check_range<T>(val: T, min: T, max: T) -> ::std::result::Result<T, String>
+where
+    T: PartialOrd + ::std::fmt::Display,
+{
+    if val != val {
+        Err("Value must be a number.".to_string())
+    } else if val < min || val > max {
+        Err(format!("Value must be between {} and {}.", min, max))
+    } else {
+        Ok(val)
+    }
+}
+
+#[cfg(test)]
+mod range_tests {
+    use super::check_range;
+
+    #[test]
+    fn accepts_value_within_bounds() {
+        assert_eq!(check_range(50, 0, 100), Ok(50));
+    }
+
+    #[test]
+    fn rejects_value_outside_bounds() {
+        assert!(check_range(150, 0, 100).is_err());
+        assert!(check_range(-1, 0, 100).is_err());
+    }
+
+    #[test]
+    fn rejects_nan() {
+        assert!(check_range(f64::NAN, 0.0, 100.0).is_err());
+    }
+}
+
+/// Prompt for a list of values, parsing a single line of comma-separated
+/// tokens into `T` via `FromStr`. Re-prompts if any token fails to parse,
+/// reporting which token was invalid.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use promptly::prompt_list;
+///
+/// let scores: Vec<u32> = prompt_list("Enter your scores")?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_list<T, S>(msg: S) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: ::std::error::Error,
+    S: AsRef<str>,
+{
+    prompt_list_sep(msg, ",")
+}
+
+/// Like [`prompt_list`], but input is empty-safe: empty input yields an empty
+/// `Vec` instead of re-prompting, and a cancelled prompt (Ctrl-C) also yields
+/// an empty `Vec`.
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_list_opt<T, S>(msg: S) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: ::std::error::Error,
+    S: AsRef<str>,
+{
+    prompt_list_opt_sep(msg, ",")
+}
+
+/// Like [`prompt_list`], but tokens are split on `sep` instead of a comma.
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_list_sep<T, S>(msg: S, sep: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: ::std::error::Error,
+    S: AsRef<str>,
+{
+    Prompter::new().prompt_then(msg, |s| {
+        if s.trim().is_empty() {
+            return Err("At least one value is required.".to_string());
+        }
+        parse_list(&s, sep)
+    })
+}
+
+/// Like [`prompt_list_opt`], but tokens are split on `sep` instead of a comma.
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_list_opt_sep<T, S>(msg: S, sep: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: ::std::error::Error,
+    S: AsRef<str>,
+{
+    match Prompter::new().prompt_then(msg, |s| {
+        if s.trim().is_empty() {
+            Ok(Vec::new())
+        } else {
+            parse_list(&s, sep)
+        }
+    }) {
+        Err(PromptError::Cancelled) => Ok(Vec::new()),
+        other => other,
+    }
+}
+
+/// Parse a `sep`-delimited line into a `Vec<T>`, reporting which token failed to parse.
+fn parse_list<T>(s: &str, sep: &str) -> ::std::result::Result<Vec<T>, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: ::std::error::Error,
+{
+    s.split(sep)
+        .map(str::trim)
+        .filter(|tok| !tok.is_empty())
+        .map(|tok| T::from_str(tok).map_err(|e| format!("Could not parse {:?} as list item: {}", tok, e)))
+        .collect()
+}
+
+/// Prompt the user to select one choice from a list.
+///
+/// Choices are rendered as a 1-indexed list and the user enters the number of
+/// their choice. Re-prompts on empty, invalid, or out-of-range input.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use promptly::prompt_select;
+///
+/// let choices = ["small", "medium", "large"];
+/// let size = prompt_select("Choose a size", &choices)?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_select<T, S>(msg: S, choices: &[T]) -> Result<T>
+where
+    T: Clone + ::std::fmt::Display,
+    S: AsRef<str>,
+{
+    prompt_select_by(msg, choices, T::to_string)
+}
+
+/// Like [`prompt_select`], but the label for each choice is produced by `label`
+/// instead of requiring `T: Display`.
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_select_by<T, S, F>(msg: S, choices: &[T], label: F) -> Result<T>
+where
+    T: Clone,
+    S: AsRef<str>,
+    F: Fn(&T) -> String,
+{
+    let prompt = render_choices(msg.as_ref(), choices, &label, "Enter a number");
+    Prompter::new().prompt_then(prompt, |s| {
+        parse_index(&s, choices.len()).map(|i| choices[i].clone())
+    })
+}
+
+/// Prompt the user to select any number of choices from a list.
+///
+/// The user enters a comma or space separated list of 1-indexed choice
+/// numbers. Empty input yields an empty `Vec`. Re-prompts on invalid or
+/// out-of-range indices.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use promptly::prompt_multiselect;
+///
+/// let choices = ["bacon", "eggs", "toast"];
+/// let picked = prompt_multiselect("Choose toppings", &choices)?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_multiselect<T, S>(msg: S, choices: &[T]) -> Result<Vec<T>>
+where
+    T: Clone + ::std::fmt::Display,
+    S: AsRef<str>,
+{
+    prompt_multiselect_by(msg, choices, T::to_string)
+}
+
+/// Like [`prompt_multiselect`], but the label for each choice is produced by
+/// `label` instead of requiring `T: Display`.
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_multiselect_by<T, S, F>(msg: S, choices: &[T], label: F) -> Result<Vec<T>>
+where
+    T: Clone,
+    S: AsRef<str>,
+    F: Fn(&T) -> String,
+{
+    let prompt = render_choices(msg.as_ref(), choices, &label, "Enter one or more numbers");
+    Prompter::new().prompt_then(prompt, |s| {
+        if s.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|tok| !tok.is_empty())
+            .map(|tok| parse_index(tok, choices.len()))
+            .map(|res| res.map(|i| choices[i].clone()))
+            .collect()
+    })
+}
+
+/// Render a 1-indexed, newline-separated list of choices for display above a prompt.
+fn render_choices<T, F>(msg: &str, choices: &[T], label: &F, hint: &str) -> String
+where
+    F: Fn(&T) -> String,
+{
+    let mut out = String::from(msg);
+    out.push('\n');
+    for (i, choice) in choices.iter().enumerate() {
+        out.push_str(&format!("  {}) {}\n", i + 1, label(choice)));
+    }
+    out.push_str(&style_hint(hint));
+    out
+}
+
+/// Parse a 1-indexed choice number into a 0-indexed `usize`, validating it
+/// falls within `[1, len]`.
+fn parse_index(s: &str, len: usize) -> ::std::result::Result<usize, String> {
+    let n: usize = s
+        .trim()
+        .parse()
+        .map_err(|_| format!("{:?} is not a valid choice number.", s.trim()))?;
+    if n < 1 || n > len {
+        return Err(format!("Choice must be between 1 and {}.", len));
+    }
+    Ok(n - 1)
+}
+
+/// Prompt using a single-key "expand" menu, where each choice is bound to
+/// a distinct character key, e.g. `(y/n/a/q)`. The answer is read as a line:
+/// the user types one key and presses Enter.
+///
+/// Entering `?` or `h` expands the compact key list into the full labeled
+/// list, then re-prompts. Empty input falls back to `default`, if given.
+///
+/// `h` and `?` are reserved for this help menu and must not be used as an
+/// item key; doing so makes that item permanently unreachable (debug builds
+/// will panic on this).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use promptly::prompt_expand;
+///
+/// let choice = prompt_expand(
+///     "Overwrite this file",
+///     &[('y', "Yes, overwrite", "yes"), ('n', "No, skip", "no"), ('a', "Yes to all", "all")],
+///     Some('n'),
+/// )?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if readline fails.
+pub fn prompt_expand<T, S>(msg: S, items: &[(char, &str, T)], default: Option<char>) -> Result<T>
+where
+    T: Clone,
+    S: AsRef<str>,
+{
+    debug_assert!(
+        items.iter().all(|(key, _, _)| !key.eq_ignore_ascii_case(&'h') && *key != '?'),
+        "prompt_expand: 'h' and '?' are reserved for the help menu and cannot be used as item keys"
+    );
+    let prompt = render_expand(msg.as_ref(), items, default);
+    Prompter::new().prompt_then(prompt, |s| match_expand(&s, items, default))
+}
+
+/// Render the compact `(y/n/a/q)`-style key list, uppercasing the default key.
+fn render_expand<T>(msg: &str, items: &[(char, &str, T)], default: Option<char>) -> String {
+    let keys: Vec<String> = items
+        .iter()
+        .map(|&(key, _, _)| {
+            if default.map_or(false, |d| d.eq_ignore_ascii_case(&key)) {
+                key.to_ascii_uppercase().to_string()
+            } else {
+                key.to_string()
+            }
+        })
+        .collect();
+    format!("{} {}", msg, style_hint(&format!("({}/h)", keys.join("/"))))
+}
+
+/// Render the full labeled list of expand options, shown when the user asks for help.
+fn render_expand_help<T>(items: &[(char, &str, T)]) -> String {
+    let mut out = style_hint("Options:");
+    out.push('\n');
+    for &(key, label, _) in items {
+        out.push_str(&format!("  {}) {}\n", key, label));
+    }
+    out.push_str(&style_hint("  h) Help, list all options"));
+    out
+}
+
+fn match_expand<T>(
+    s: &str,
+    items: &[(char, &str, T)],
+    default: Option<char>,
+) -> ::std::result::Result<T, String>
+where
+    T: Clone,
+{
+    let s = s.trim();
+    if s.is_empty() {
+        if let Some(default) = default {
+            if let Some((_, _, val)) = items.iter().find(|(key, _, _)| key.eq_ignore_ascii_case(&default)) {
+                return Ok(val.clone());
+            }
+        }
+        return Err("A choice is required.".to_string());
+    }
+
+    if s.eq_ignore_ascii_case("?") || s.eq_ignore_ascii_case("h") {
+        eprintln!("{}", render_expand_help(items));
+        return Err("Enter one of the keys above.".to_string());
+    }
+
+    let mut chars = s.chars();
+    let key = chars.next().filter(|_| chars.as_str().is_empty());
+    match key {
+        Some(key) => items
+            .iter()
+            .find(|(item_key, _, _)| item_key.eq_ignore_ascii_case(&key))
+            .map(|(_, _, val)| val.clone())
+            .ok_or_else(|| format!("{:?} is not a valid option.", s)),
+        None => Err(format!("{:?} is not a valid option.", s)),
+    }
+}
+
 /// A trait for convenient, opinionated prompting
 pub trait Promptable: Sized {
     /// Prompts for a value. Re-prompts on invalid and empty input.
@@ -183,7 +607,7 @@ where
     ///
     /// Default value is visible in the prompt as: `(default=USA)`
     fn prompt_default<S: AsRef<str>>(msg: S, default: Self) -> Result<Self> {
-        let msg = format!("{} (default={})", msg.as_ref(), default);
+        let msg = format!("{} {}", msg.as_ref(), style_hint(&format!("(default={})", default)));
         prompt_parse_opt(msg).unwrap_or(default)
     }
 }
@@ -208,7 +632,10 @@ impl Promptable for String {
     /// # Result::<_,Box<std::error::Error>>::Ok(())
     /// ```
     fn prompt_opt<S: AsRef<str>>(msg: S) -> Result<Option<Self>> {
-        Prompter::new().prompt_opt(msg)
+        match Prompter::new().prompt_opt(msg) {
+            Err(PromptError::Cancelled) => Ok(None),
+            other => other,
+        }
     }
 
     /// Prompt for a string with a provided fallback value if empty.
@@ -221,8 +648,12 @@ impl Promptable for String {
     ///
     /// Default value is visible in the prompt as: `(default=USA)`
     fn prompt_default<S: AsRef<str>>(msg: S, default: Self) -> Result<Self> {
-        let msg = format!("{} (default={})", msg.as_ref(), default);
-        Ok(Prompter::new().prompt_opt(msg)?.unwrap_or(default))
+        let msg = format!("{} {}", msg.as_ref(), style_hint(&format!("(default={})", default)));
+        match Prompter::new().prompt_opt(msg) {
+            Ok(val) => Ok(val.unwrap_or(default)),
+            Err(PromptError::Cancelled) => Ok(default),
+            Err(e) => Err(e),
+        }
     }
 }
 
@@ -238,7 +669,11 @@ impl Promptable for PathBuf {
     }
     /// Prompt for a path with a provided fallback value if empty
     fn prompt_default<S: AsRef<str>>(msg: S, default: Self) -> Result<Self> {
-        let msg = format!("{} (default={})", msg.as_ref(), default.display());
+        let msg = format!(
+            "{} {}",
+            msg.as_ref(),
+            style_hint(&format!("(default={})", default.display()))
+        );
         Ok(prompt_path_opt(msg)?.unwrap_or(default))
     }
 }
@@ -282,9 +717,9 @@ impl Promptable for bool {
     /// ```
     fn prompt_default<S: AsRef<str>>(msg: S, default: Self) -> Result<Self> {
         let msg = if default {
-            format!("{} (Y/n)", msg.as_ref())
+            format!("{} {}", msg.as_ref(), style_hint("(Y/n)"))
         } else {
-            format!("{} (y/N)", msg.as_ref())
+            format!("{} {}", msg.as_ref(), style_hint("(y/N)"))
         };
         Ok(prompt_bool_opt(msg)?.unwrap_or(default))
     }
@@ -303,7 +738,7 @@ macro_rules! impl_promptable_from_str {
             }
 
             fn prompt_default<S: AsRef<str>>(msg: S, default: Self) -> Result<Self> {
-                let msg = format!("{} (default={})", msg.as_ref(), default);
+                let msg = format!("{} {}", msg.as_ref(), style_hint(&format!("(default={})", default)));
                 Ok(prompt_parse_opt(msg)?.unwrap_or(default))
             }
         }
@@ -346,9 +781,147 @@ impl_promptable_from_str!(::std::num::NonZeroUsize);
 #[cfg(feature = "url")]
 impl_promptable_from_str!(url::Url);
 
+/// A validator checks raw input before it's parsed, rejecting it with a message
+/// that is shown to the user before re-prompting.
+type Validator = Box<dyn Fn(&str) -> ::std::result::Result<(), String>>;
+
+/// An ANSI color for use in a [`PromptStyle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn ansi_code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Blue => "34",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+            Color::White => "37",
+        }
+    }
+}
+
+/// Controls the visual presentation of prompts: the prefix glyph, the colors
+/// used for the message, the default/option hints, and re-prompt error
+/// messages.
+///
+/// Use [`PromptStyle::colored`] for the default colorized theme,
+/// [`PromptStyle::plain`] to disable all styling, or [`PromptStyle::auto`] to
+/// pick between the two based on whether stdout is a TTY. `PromptStyle`'s
+/// `Default` impl is `PromptStyle::auto()`.
+#[derive(Debug, Clone)]
+pub struct PromptStyle {
+    pub prefix: String,
+    pub message_color: Option<Color>,
+    pub hint_color: Option<Color>,
+    pub error_color: Option<Color>,
+}
+
+impl PromptStyle {
+    /// A `[?]`-prefixed theme with cyan hints and red errors
+    pub fn colored() -> Self {
+        PromptStyle {
+            prefix: "[?] ".to_string(),
+            message_color: None,
+            hint_color: Some(Color::Cyan),
+            error_color: Some(Color::Red),
+        }
+    }
+
+    /// No prefix, no colors; safe for non-TTY output
+    pub fn plain() -> Self {
+        PromptStyle {
+            prefix: String::new(),
+            message_color: None,
+            hint_color: None,
+            error_color: None,
+        }
+    }
+
+    /// `colored()` if stdout is a TTY, otherwise `plain()`
+    pub fn auto() -> Self {
+        use std::io::IsTerminal;
+        if ::std::io::stdout().is_terminal() {
+            PromptStyle::colored()
+        } else {
+            PromptStyle::plain()
+        }
+    }
+
+    fn colorize(color: Option<Color>, s: &str) -> String {
+        match color {
+            Some(c) => format!("\x1b[{}m{}\x1b[0m", c.ansi_code(), s),
+            None => s.to_owned(),
+        }
+    }
+
+    fn render_message<S: AsRef<str>>(&self, msg: S) -> String {
+        format!(
+            "{}{}: ",
+            self.prefix,
+            PromptStyle::colorize(self.message_color, msg.as_ref())
+        )
+    }
+
+    /// Colorize a hint fragment (e.g. `(default=...)`, `(y/n)`) for appending to a message
+    pub fn hint(&self, s: &str) -> String {
+        PromptStyle::colorize(self.hint_color, s)
+    }
+
+    fn render_error(&self, msg: &str) -> String {
+        PromptStyle::colorize(self.error_color, msg)
+    }
+}
+
+impl Default for PromptStyle {
+    fn default() -> Self {
+        PromptStyle::auto()
+    }
+}
+
+::std::thread_local! {
+    static CURRENT_STYLE: ::std::cell::RefCell<PromptStyle> = ::std::cell::RefCell::new(PromptStyle::default());
+}
+
+/// Set the `PromptStyle` used by every prompt built through the crate's free
+/// functions (`prompt`, `prompt_opt`, `prompt_default`, `prompt_range`,
+/// `prompt_list`, `prompt_select`, `prompt_expand`, every `Promptable` impl,
+/// ...) and by `Prompter::new()`/`Prompter::with_completer`, without having to
+/// thread a `Prompter` through manually.
+///
+/// Scoped to the current thread; call again to change the theme, or use
+/// [`Prompter::with_style`] for a one-off override on a single `Prompter`.
+pub fn set_style(style: PromptStyle) {
+    CURRENT_STYLE.with(|s| *s.borrow_mut() = style);
+}
+
+/// The `PromptStyle` currently in effect, as set by [`set_style`], defaulting
+/// to [`PromptStyle::auto`].
+fn current_style() -> PromptStyle {
+    CURRENT_STYLE.with(|s| s.borrow().clone())
+}
+
+/// Colorize a hint fragment (e.g. `(default=...)`) using the style in effect,
+/// for callers that build up a prompt message before a `Prompter` exists.
+fn style_hint(s: &str) -> String {
+    current_style().hint(s)
+}
+
 /// Optinionated wrapper around rustyline to prompt for strings
 pub struct Prompter<C: Completer> {
     editor: Editor<C>,
+    validator: Option<Validator>,
+    style: PromptStyle,
 }
 
 impl Prompter<()> {
@@ -361,6 +934,8 @@ impl Default for Prompter<()> {
     fn default() -> Self {
         Prompter {
             editor: Editor::new(),
+            validator: None,
+            style: current_style(),
         }
     }
 }
@@ -372,16 +947,73 @@ where
     pub fn with_completer(completer: C) -> Prompter<C> {
         let mut editor = Editor::new();
         editor.set_completer(Some(completer));
-        Prompter { editor }
+        Prompter {
+            editor,
+            validator: None,
+            style: current_style(),
+        }
+    }
+
+    /// Replace the prompt's `PromptStyle` theme
+    pub fn with_style(mut self, style: PromptStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Attach a validator that runs against the raw input before it is parsed.
+    ///
+    /// Return `Err(message)` from the validator to reject the input; the
+    /// message is printed and the prompt re-asked, same as a failed `FromStr`
+    /// parse. The validator is applied by `prompt_then`; `prompt_once`,
+    /// `prompt_opt` and `prompt_nonempty` do not run it.
+    ///
+    /// ```no_run
+    /// use promptly::Prompter;
+    ///
+    /// let name: String = Prompter::new()
+    ///     .with_validator(|s| {
+    ///         if s.len() <= 20 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Must be 20 characters or fewer.".to_string())
+    ///         }
+    ///     })
+    ///     .prompt_then("Enter a username", |s| Ok(s))?;
+    /// # Result::<_,Box<std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> ::std::result::Result<(), String> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Attach a validator that requires input to match the given regex `pattern`.
+    #[cfg(feature = "regex")]
+    pub fn with_pattern(self, pattern: &str) -> ::std::result::Result<Self, regex::Error> {
+        let re = regex::Regex::new(pattern)?;
+        Ok(self.with_validator(move |s| {
+            if re.is_match(s) {
+                Ok(())
+            } else {
+                Err(format!("Input must match pattern: {}", re.as_str()))
+            }
+        }))
     }
 
     pub fn prompt_once<S: AsRef<str>>(&mut self, msg: S) -> Result<String> {
         self.editor
-            .readline(&format!("{}: ", msg.as_ref()))
+            .readline(&self.style.render_message(msg))
             .map(|line| line.trim().to_owned())
+            .map_err(PromptError::from)
     }
 
     /// Prompts once but returns `None` for empty input
+    ///
+    /// A cancelled prompt (Ctrl-C) still propagates as `Err(PromptError::Cancelled)`;
+    /// callers that want cancellation to read as `None` should match on it themselves,
+    /// as the various `_opt` free functions do.
     pub fn prompt_opt<S: AsRef<str>>(&mut self, msg: S) -> Result<Option<String>> {
         let val = self.prompt_once(msg)?;
         if val.is_empty() {
@@ -395,24 +1027,34 @@ where
         let mut val;
         val = self.prompt_opt(&msg)?;
         while val.is_none() {
-            eprintln!("Value is required.");
+            eprintln!("{}", self.style.render_error("Value is required."));
             val = self.prompt_opt(&msg)?;
         }
         Ok(val.unwrap())
     }
 
     /// Prompts with custom handler to transform input
+    ///
+    /// If a validator has been attached via `with_validator`, it runs against
+    /// the raw input before `handler` is called.
     pub fn prompt_then<S, F, U>(&mut self, msg: S, handler: F) -> Result<U>
     where
         S: AsRef<str>,
         F: Fn(String) -> ::std::result::Result<U, String>,
     {
-        let mut val = handler(self.prompt_once(&msg)?);
-        while let Err(e) = val {
-            eprintln!("{}", e);
-            val = handler(self.prompt_once(&msg)?);
+        loop {
+            let line = self.prompt_once(&msg)?;
+            if let Some(ref validator) = self.validator {
+                if let Err(e) = validator(&line) {
+                    eprintln!("{}", self.style.render_error(&e));
+                    continue;
+                }
+            }
+            match handler(line) {
+                Ok(val) => return Ok(val),
+                Err(e) => eprintln!("{}", self.style.render_error(&e)),
+            }
         }
-        Ok(val.unwrap())
     }
 }
 
@@ -429,12 +1071,15 @@ fn prompt_bool<S: AsRef<str>>(msg: S) -> Result<bool> {
 }
 
 fn prompt_bool_opt<S: AsRef<str>>(msg: S) -> Result<Option<bool>> {
-    Prompter::new().prompt_then(msg, |s| match &*s.to_lowercase().trim() {
+    match Prompter::new().prompt_then(msg, |s| match &*s.to_lowercase().trim() {
         "" => Ok(None),
         "true" | "yes" | "y" => Ok(Some(true)),
         "false" | "no" | "n" => Ok(Some(false)),
         s => Err(format!("Could not parse {} as bool.", s)),
-    })
+    }) {
+        Err(PromptError::Cancelled) => Ok(None),
+        other => other,
+    }
 }
 
 fn prompt_path<S: AsRef<str>>(msg: S) -> Result<PathBuf> {
@@ -445,10 +1090,11 @@ fn prompt_path<S: AsRef<str>>(msg: S) -> Result<PathBuf> {
 
 fn prompt_path_opt<S: AsRef<str>>(msg: S) -> Result<Option<PathBuf>> {
     let completer = FilenameCompleter::new();
-    Ok(Prompter::with_completer(completer)
-        .prompt_opt(msg)?
-        .map(path_expand)
-        .map(PathBuf::from))
+    match Prompter::with_completer(completer).prompt_opt(msg) {
+        Ok(val) => Ok(val.map(path_expand).map(PathBuf::from)),
+        Err(PromptError::Cancelled) => Ok(None),
+        Err(e) => Err(e),
+    }
 }
 
 fn prompt_parse<T, S>(msg: S) -> Result<T>
@@ -466,13 +1112,16 @@ where
     <T as FromStr>::Err: ::std::error::Error,
     S: AsRef<str>,
 {
-    Prompter::new().prompt_then(msg, |s| match s.trim() {
+    match Prompter::new().prompt_then(msg, |s| match s.trim() {
         "" => Ok(None),
         _ => match T::from_str(s.as_ref()) {
             Ok(n) => Ok(Some(n)),
             Err(e) => Err(e.to_string()),
         },
-    })
+    }) {
+        Err(PromptError::Cancelled) => Ok(None),
+        other => other,
+    }
 }
 
 fn path_expand(s: String) -> String {
@@ -483,3 +1132,82 @@ fn path_expand(s: String) -> String {
     }
     s
 }
+
+/*
+ * Secret / password prompting
+ */
+
+/// A secret value (such as a password) that is zeroized on drop so it doesn't
+/// linger in memory longer than necessary.
+///
+/// Obtained via [`prompt_password`] or [`prompt_password_confirm`].
+#[cfg(feature = "rpassword")]
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub struct Secret(String);
+
+#[cfg(feature = "rpassword")]
+impl Secret {
+    /// Borrow the underlying secret value
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "rpassword")]
+impl ::std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str("Secret(..)")
+    }
+}
+
+/// Prompt for a password, reading a line with echo disabled.
+///
+/// Empty input is rejected and re-prompted unless `allow_empty` is `true`.
+///
+/// ```no_run
+/// use promptly::prompt_password;
+/// let password = prompt_password("Enter your password", false)?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if reading from the terminal fails.
+#[cfg(feature = "rpassword")]
+pub fn prompt_password<S: AsRef<str>>(msg: S, allow_empty: bool) -> Result<Secret> {
+    loop {
+        let pass = read_password(msg.as_ref())?;
+        if pass.is_empty() && !allow_empty {
+            eprintln!("{}", current_style().render_error("Value is required."));
+            continue;
+        }
+        return Ok(Secret(pass));
+    }
+}
+
+/// Prompt for a password twice, re-prompting until both entries match.
+///
+/// ```no_run
+/// use promptly::prompt_password_confirm;
+/// let password = prompt_password_confirm("Choose a password", false)?;
+/// # Result::<_,Box<std::error::Error>>::Ok(())
+/// ```
+///
+/// ## Errors
+/// Returns a `PromptError` if reading from the terminal fails.
+#[cfg(feature = "rpassword")]
+pub fn prompt_password_confirm<S: AsRef<str>>(msg: S, allow_empty: bool) -> Result<Secret> {
+    loop {
+        let first = prompt_password(msg.as_ref(), allow_empty)?;
+        let second = prompt_password("Confirm password", allow_empty)?;
+        if first.expose() == second.expose() {
+            return Ok(first);
+        }
+        eprintln!("{}", current_style().render_error("Passwords did not match."));
+    }
+}
+
+#[cfg(feature = "rpassword")]
+fn read_password(msg: &str) -> Result<String> {
+    rpassword::read_password_from_tty(Some(&current_style().render_message(msg))).map_err(PromptError::Io)
+}